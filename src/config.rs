@@ -0,0 +1,60 @@
+use std::{env, fmt, net::SocketAddr};
+
+// Application configuration, resolved once at startup from the process environment
+// so handlers and middleware read typed fields instead of calling `env::var` (and
+// panicking on a missing variable) on every request.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub client_id: String,
+    pub client_secret: String,
+    pub user_pool_id: String,
+    pub user_pool_region: String,
+    pub db_url: String,
+    pub bind_address: SocketAddr,
+    pub cors_origin: String,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            client_id: required_var("CLIENT_ID")?,
+            client_secret: required_var("CLIENT_SECRET")?,
+            user_pool_id: required_var("USER_POOL_ID")?,
+            user_pool_region: required_var("USER_POOL_REGION")?,
+            db_url: env::var("DB_URL").unwrap_or_else(|_| "sqlite://todo.db".to_string()),
+            bind_address: optional_var("BIND_ADDRESS", "127.0.0.1:3000")
+                .parse()
+                .map_err(|_| ConfigError::Invalid("BIND_ADDRESS".to_string()))?,
+            cors_origin: optional_var("CORS_ORIGIN", "http://localhost:3000"),
+        })
+    }
+}
+
+fn required_var(name: &str) -> Result<String, ConfigError> {
+    env::var(name).map_err(|_| ConfigError::Missing(name.to_string()))
+}
+
+fn optional_var(name: &str, default: &str) -> String {
+    env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Missing(String),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(name) => {
+                write!(f, "missing required environment variable: {}", name)
+            }
+            ConfigError::Invalid(name) => {
+                write!(f, "invalid value for environment variable: {}", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,62 @@
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use crate::{handler, model::Todo, schema};
+
+// Machine-readable description of the HTTP API, served as a Swagger UI by `create_router`.
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::health_checker_handler,
+        handler::readiness_handler,
+        handler::get_todos,
+        handler::create_todo,
+        handler::get_todo,
+        handler::update_todo,
+        handler::delete_todo,
+        handler::todo_events,
+        handler::login,
+        handler::refresh,
+        handler::signup,
+        handler::confirm_user,
+        handler::forgot_password,
+        handler::confirm_forgot_password,
+    ),
+    components(schemas(
+        Todo,
+        schema::CreateTodoSchema,
+        schema::UpdateTodoSchema,
+        schema::SignupSchema,
+        schema::ConfirmUserSchema,
+        schema::LoginSchema,
+        schema::RefreshSchema,
+        schema::ForgotPasswordSchema,
+        schema::ConfirmForgotPasswordSchema,
+    )),
+    tags(
+        (name = "health", description = "Liveness and readiness checks"),
+        (name = "todos", description = "Per-user todo CRUD"),
+        (name = "auth", description = "Cognito-backed signup/login flow"),
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.as_mut().expect("components exist");
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
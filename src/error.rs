@@ -0,0 +1,63 @@
+use axum::{http::StatusCode, response::IntoResponse, Json};
+use serde_json::json;
+
+// Centralized application error type. Every fallible handler returns this as its
+// Err variant so error responses share a single `{"status","message"}` shape.
+#[derive(Debug)]
+pub enum AppError {
+    NotFound(String),
+    Conflict(String),
+    InvalidCredentials,
+    MissingToken,
+    InvalidToken,
+    Database(sqlx::Error),
+    Cognito(String),
+    Validation(String),
+    Internal(String),
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, message) = match self {
+            AppError::NotFound(message) => (StatusCode::NOT_FOUND, message),
+            AppError::Conflict(message) => (StatusCode::CONFLICT, message),
+            AppError::InvalidCredentials => (
+                StatusCode::UNAUTHORIZED,
+                "Invalid username or password".to_string(),
+            ),
+            AppError::MissingToken => (
+                StatusCode::UNAUTHORIZED,
+                "Missing Authorization token".to_string(),
+            ),
+            AppError::InvalidToken => (
+                StatusCode::UNAUTHORIZED,
+                "Invalid or expired token".to_string(),
+            ),
+            AppError::Database(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("{:?}", err))
+            }
+            AppError::Cognito(message) => (StatusCode::BAD_REQUEST, message),
+            AppError::Validation(message) => (StatusCode::UNPROCESSABLE_ENTITY, message),
+            AppError::Internal(message) => (StatusCode::INTERNAL_SERVER_ERROR, message),
+        };
+
+        let body = Json(json!({
+            "status": if status.is_success() { "success" } else { "fail" },
+            "message": message
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        match &err {
+            sqlx::Error::RowNotFound => AppError::NotFound("Resource not found".to_string()),
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict("Todo with that title already exists".to_string())
+            }
+            _ => AppError::Database(err),
+        }
+    }
+}
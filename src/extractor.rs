@@ -0,0 +1,61 @@
+use axum::{
+    async_trait,
+    body::HttpBody,
+    extract::{FromRequest, Json},
+    http::Request,
+    BoxError,
+};
+use serde::de::DeserializeOwned;
+use validator::{Validate, ValidationErrors};
+
+use crate::error::AppError;
+
+// Deserializes a JSON body and runs `validator::Validate` on it, rejecting with a
+// 422 `AppError::Validation` that lists the failing fields instead of letting bad
+// input reach handlers or downstream services.
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|err| AppError::Validation(err.to_string()))?;
+
+        value
+            .validate()
+            .map_err(|errors| AppError::Validation(format_validation_errors(&errors)))?;
+
+        Ok(ValidatedJson(value))
+    }
+}
+
+fn format_validation_errors(errors: &ValidationErrors) -> String {
+    errors
+        .field_errors()
+        .iter()
+        .map(|(field, field_errors)| {
+            let messages: Vec<String> = field_errors
+                .iter()
+                .map(|error| {
+                    error
+                        .message
+                        .clone()
+                        .map(|message| message.to_string())
+                        .unwrap_or_else(|| error.code.to_string())
+                })
+                .collect();
+            format!("{}: {}", field, messages.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -1,26 +1,47 @@
-use std::sync::Arc;
+use std::{convert::Infallible, sync::Arc};
 
 use aws_sdk_cognitoidentityprovider::types::builders::AttributeTypeBuilder;
+use aws_sdk_cognitoidentityprovider::types::AuthFlowType;
 use aws_sdk_cognitoidentityprovider::types::AuthFlowType::UserPasswordAuth;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Extension, Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
     Json,
 };
 use base64::{engine::general_purpose, Engine};
+use futures_util::Stream;
 use ring::hmac;
 use serde_json::json;
-use sqlx::{query, query_as};
+use sqlx::{query, query_as, QueryBuilder, Sqlite};
+use tokio_stream::{wrappers::BroadcastStream, StreamExt};
 
 use crate::{
-    model::Todo,
-    schema::{ConfirmUserSchema, CreateTodoSchema, SignupSchema, UpdateTodoSchema, LoginSchema},
+    error::AppError,
+    extractor::ValidatedJson,
+    model::{CurrentUser, Todo, TodoEvent},
+    schema::{
+        ConfirmForgotPasswordSchema, ConfirmUserSchema, CreateTodoSchema, ForgotPasswordSchema,
+        ListOptions, LoginSchema, RefreshSchema, SignupSchema, UpdateTodoSchema,
+    },
     AppState,
 };
 
+// Default and maximum page size for `GET /todos`.
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
 // Handler for the health checker route
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "health",
+    responses((status = 200, description = "Service is alive"))
+)]
 pub async fn health_checker_handler() -> impl IntoResponse {
     const MESSAGE: &str = "Simple CRUD API with Rust, SQLX, Postgres, and Axum";
 
@@ -32,181 +53,309 @@ pub async fn health_checker_handler() -> impl IntoResponse {
     Json(json_response)
 }
 
-// Handler for getting all Todo items
+// Handler for the readiness probe route; actually exercises each dependency rather
+// than returning a static success message like `health_checker_handler` does.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Database and Cognito are both reachable"),
+        (status = 503, description = "At least one dependency is unreachable")
+    )
+)]
+pub async fn readiness_handler(State(data): State<Arc<AppState>>) -> impl IntoResponse {
+    let database_ready = query("SELECT 1").execute(&data.db).await.is_ok();
+    let cognito_ready = data
+        .client
+        .list_user_pools()
+        .max_results(1)
+        .send()
+        .await
+        .is_ok();
+
+    let ready = database_ready && cognito_ready;
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    let body = json!({
+        "status": if ready { "success" } else { "fail" },
+        "checks": {
+            "database": if database_ready { "ok" } else { "unreachable" },
+            "cognito": if cognito_ready { "ok" } else { "unreachable" }
+        }
+    });
+
+    (status, Json(body))
+}
+
+// Handler for getting a page of the current user's Todo items
+#[utoipa::path(
+    get,
+    path = "/todos",
+    tag = "todos",
+    params(ListOptions),
+    responses(
+        (status = 200, description = "Page of the current user's todos"),
+        (status = 401, description = "Missing or invalid bearer token")
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn get_todos(
     State(data): State<Arc<AppState>>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    // Fetch all Todo items from the database
-    let todos_result = query_as::<_, Todo>("SELECT id, task, completed FROM todos")
-        .fetch_all(&data.db)
-        .await;
-    if todos_result.is_err() {
-        // Handle error response if fetching todos fails
-        let error_response = serde_json::json!({
-            "status": "fail",
-            "message": "Something bad happened while fetching all todo items",
-        });
-        return Err((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)));
+    Extension(current_user): Extension<CurrentUser>,
+    Query(opts): Query<ListOptions>,
+) -> Result<impl IntoResponse, AppError> {
+    let limit = opts.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let offset = opts.offset.unwrap_or(0).max(0);
+    // Only allow sorting by a known, safe set of columns since `sort` can't be bound as a parameter.
+    let sort_column = match opts.sort.as_deref() {
+        Some("task") => "task",
+        _ => "id",
+    };
+
+    let mut count_builder: QueryBuilder<Sqlite> =
+        QueryBuilder::new("SELECT COUNT(*) FROM todos WHERE username = ");
+    count_builder.push_bind(&current_user.username);
+    if let Some(completed) = opts.completed {
+        count_builder.push(" AND completed = ").push_bind(completed);
     }
+    let total: i64 = count_builder
+        .build_query_scalar()
+        .fetch_one(&data.db)
+        .await?;
+
+    let mut rows_builder: QueryBuilder<Sqlite> =
+        QueryBuilder::new("SELECT id, task, completed FROM todos WHERE username = ");
+    rows_builder.push_bind(&current_user.username);
+    if let Some(completed) = opts.completed {
+        rows_builder.push(" AND completed = ").push_bind(completed);
+    }
+    rows_builder
+        .push(format!(" ORDER BY {} LIMIT ", sort_column))
+        .push_bind(limit)
+        .push(" OFFSET ")
+        .push_bind(offset);
+
+    let todos = rows_builder
+        .build_query_as::<Todo>()
+        .fetch_all(&data.db)
+        .await?;
 
-    // Prepare success response with fetched todos
-    let todos = todos_result.unwrap();
     let json_response = serde_json::json!({
         "status": "success",
         "results": todos.len(),
+        "total": total,
+        "offset": offset,
+        "limit": limit,
         "todos": todos
     });
     Ok((StatusCode::OK, Json(json_response)))
 }
 
-// Handler for creating a new Todo
+// Handler for creating a new Todo owned by the current user
+#[utoipa::path(
+    post,
+    path = "/todos",
+    tag = "todos",
+    request_body = CreateTodoSchema,
+    responses(
+        (status = 201, description = "Todo created; the todo is returned under `data.todo`"),
+        (status = 401, description = "Missing or invalid bearer token")
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn create_todo(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<CreateTodoSchema>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    Extension(current_user): Extension<CurrentUser>,
+    ValidatedJson(body): ValidatedJson<CreateTodoSchema>,
+) -> Result<impl IntoResponse, AppError> {
     // Insert a new Todo into the database
-    let todo_result = query_as::<_, Todo>(
-        "INSERT INTO todos (task, completed) VALUES (?, ?) RETURNING id, task, completed",
+    let todo = query_as::<_, Todo>(
+        "INSERT INTO todos (task, completed, username) VALUES (?, ?, ?) RETURNING id, task, completed",
     )
     .bind(body.task)
     .bind(body.completed)
+    .bind(&current_user.username)
     .fetch_one(&data.db)
-    .await;
+    .await?;
 
-    // Handle the result and prepare the response
-    match todo_result {
-        Ok(todo) => {
-            let todo_response = json!({"status": "success","data": json!({
-                "todo": todo
-            })});
+    let _ = data.todo_events.send(TodoEvent {
+        action: "created".to_string(),
+        id: todo.id,
+        username: current_user.username,
+    });
 
-            Ok((StatusCode::CREATED, Json(todo_response)))
-        }
-        Err(e) => {
-            // Handle specific error cases and prepare error response
-            if e.to_string()
-                .contains("duplicate key value violates unique constraint")
-            {
-                let error_response = serde_json::json!({
-                    "status": "fail",
-                    "message": "Todo with that title already exists",
-                });
-                Err((StatusCode::CONFLICT, Json(error_response)))
-            } else {
-                Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(json!({"status": "error","message": format!("{:?}", e)})),
-                ))
-            }
-        }
-    }
+    let todo_response = json!({"status": "success","data": json!({
+        "todo": todo
+    })});
+
+    Ok((StatusCode::CREATED, Json(todo_response)))
 }
 
-// Handler for getting a specific Todo by ID
+// Handler for getting a specific Todo by ID, scoped to the current user
+#[utoipa::path(
+    get,
+    path = "/todos/{id}",
+    tag = "todos",
+    params(("id" = i32, Path, description = "Todo id")),
+    responses(
+        (status = 200, description = "Todo found; the todo is returned under `data.todo`"),
+        (status = 404, description = "Todo not found"),
+        (status = 401, description = "Missing or invalid bearer token")
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn get_todo(
     Path(id): Path<i32>,
     State(data): State<Arc<AppState>>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    // Fetch a Todo by ID from the database
-    let todo_result =
-        sqlx::query_as::<_, Todo>("SELECT id, task, completed FROM todos where id = ?")
-            .bind(id)
-            .fetch_one(&data.db)
-            .await;
-
-    // Handle the result and prepare the response
-    match todo_result {
-        Ok(todo) => {
-            let todo_response = serde_json::json!({"status": "success","data": serde_json::json!({
-                "todo": todo
-            })});
+    Extension(current_user): Extension<CurrentUser>,
+) -> Result<impl IntoResponse, AppError> {
+    // Fetch a Todo by ID from the database, scoped to its owner
+    let todo = sqlx::query_as::<_, Todo>(
+        "SELECT id, task, completed FROM todos WHERE id = ? AND username = ?",
+    )
+    .bind(id)
+    .bind(&current_user.username)
+    .fetch_one(&data.db)
+    .await
+    .map_err(|_| AppError::NotFound(format!("Todo with ID: {} not found", id)))?;
 
-            Ok((StatusCode::OK, Json(todo_response)))
-        }
-        Err(_) => {
-            // Handle the case when the Todo with the specified ID is not found
-            let error_response = serde_json::json!({
-                "status": "fail",
-                "message": format!("Todo with ID: {} not found", id)
-            });
-            Err((StatusCode::NOT_FOUND, Json(error_response)))
-        }
-    }
+    let todo_response = serde_json::json!({"status": "success","data": serde_json::json!({
+        "todo": todo
+    })});
+
+    Ok((StatusCode::OK, Json(todo_response)))
 }
 
-// Handler for updating a Todo by ID
+// Handler for updating a Todo by ID, scoped to the current user
+#[utoipa::path(
+    patch,
+    path = "/todos/{id}",
+    tag = "todos",
+    params(("id" = i32, Path, description = "Todo id")),
+    request_body = UpdateTodoSchema,
+    responses(
+        (status = 200, description = "Todo updated; the todo is returned under `data.todo`"),
+        (status = 404, description = "Todo not found"),
+        (status = 401, description = "Missing or invalid bearer token")
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn update_todo(
     Path(id): Path<i32>,
     State(data): State<Arc<AppState>>,
-    Json(body): Json<UpdateTodoSchema>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    // Update a Todo by ID in the database
-    let todo_result = query_as::<_, Todo>(
-        "UPDATE todos SET task = ?, completed = ? WHERE id = ? RETURNING id, task, completed",
+    Extension(current_user): Extension<CurrentUser>,
+    ValidatedJson(body): ValidatedJson<UpdateTodoSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    // Update a Todo by ID in the database, scoped to its owner
+    let todo = query_as::<_, Todo>(
+        "UPDATE todos SET task = ?, completed = ? WHERE id = ? AND username = ? RETURNING id, task, completed",
     )
     .bind(body.task)
     .bind(body.completed)
     .bind(id)
+    .bind(&current_user.username)
     .fetch_one(&data.db)
-    .await;
+    .await
+    .map_err(|_| AppError::NotFound(format!("Todo with ID: {} not found", id)))?;
 
-    // Handle the result and prepare the response
-    match todo_result {
-        Ok(todo) => {
-            let todo_response = serde_json::json!({"status": "success","data": serde_json::json!({
-                "todo": todo
-            })});
+    let _ = data.todo_events.send(TodoEvent {
+        action: "updated".to_string(),
+        id: todo.id,
+        username: current_user.username,
+    });
 
-            Ok(Json(todo_response))
-        }
-        Err(err) => {
-            // Handle the case when the update operation fails
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(json!({"status": "error","message": format!("{:?}", err)})),
-            ))
-        }
-    }
+    let todo_response = serde_json::json!({"status": "success","data": serde_json::json!({
+        "todo": todo
+    })});
+
+    Ok(Json(todo_response))
 }
 
-// Handler for deleting a Todo by ID
+// Handler for deleting a Todo by ID, scoped to the current user
+#[utoipa::path(
+    delete,
+    path = "/todos/{id}",
+    tag = "todos",
+    params(("id" = i32, Path, description = "Todo id")),
+    responses(
+        (status = 204, description = "Todo deleted"),
+        (status = 404, description = "Todo not found"),
+        (status = 401, description = "Missing or invalid bearer token")
+    ),
+    security(("bearer_auth" = []))
+)]
 pub async fn delete_todo(
     Path(id): Path<i32>,
     State(data): State<Arc<AppState>>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    // Delete a Todo by ID from the database
-    let rows_affected = query("DELETE FROM todos WHERE id = ?")
+    Extension(current_user): Extension<CurrentUser>,
+) -> Result<impl IntoResponse, AppError> {
+    // Delete a Todo by ID from the database, scoped to its owner
+    let rows_affected = query("DELETE FROM todos WHERE id = ? AND username = ?")
         .bind(id)
+        .bind(&current_user.username)
         .execute(&data.db)
-        .await
-        .unwrap()
+        .await?
         .rows_affected();
     if rows_affected == 0 {
-        // Handle the case when the Todo with the specified ID is not found
-        let error_response = serde_json::json!({
-            "status": "error",
-            "message": format!("Note with ID: {} not found", id)
-        });
-        return Err((StatusCode::NOT_FOUND, Json(error_response)));
+        return Err(AppError::NotFound(format!("Todo with ID: {} not found", id)));
     }
 
+    let _ = data.todo_events.send(TodoEvent {
+        action: "deleted".to_string(),
+        id,
+        username: current_user.username,
+    });
+
     Ok(StatusCode::NO_CONTENT)
 }
 
-pub async fn login(
+// Handler streaming todo create/update/delete events for the current user over SSE
+#[utoipa::path(
+    get,
+    path = "/todos/events",
+    tag = "todos",
+    responses((status = 200, description = "SSE stream of todo events for the current user")),
+    security(("bearer_auth" = []))
+)]
+pub async fn todo_events(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<LoginSchema>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    Extension(current_user): Extension<CurrentUser>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let username = current_user.username;
+    let stream = BroadcastStream::new(data.todo_events.subscribe()).filter_map(move |message| {
+        match message {
+            Ok(event) if event.username == username => {
+                Event::default().json_data(&event).ok().map(Ok)
+            }
+            _ => None,
+        }
+    });
 
-    let client_id = std::env::var("CLIENT_ID").unwrap();
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
 
-    let client_secret = generate_secret_hash(
-        &std::env::var("CLIENT_SECRET").unwrap(),
-        &body.username,
-        &client_id,
-    );
+#[utoipa::path(
+    post,
+    path = "/login",
+    tag = "auth",
+    request_body = LoginSchema,
+    responses(
+        (status = 200, description = "Login succeeded, returns access/id/refresh tokens"),
+        (status = 401, description = "Invalid username or password")
+    )
+)]
+pub async fn login(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<LoginSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_id = data.config.client_id.clone();
 
-    let _user_pool_id = std::env::var("USER_POOL_ID").unwrap();
+    let client_secret = generate_secret_hash(&data.config.client_secret, &body.username, &client_id);
 
     let initiate_auth_fluent_builder = data.client.initiate_auth()
     .client_id(client_id)
@@ -214,8 +363,8 @@ pub async fn login(
     .auth_parameters("USERNAME",&body.username)
     .auth_parameters("PASSWORD", &body.password)
     .auth_parameters("SECRET_HASH", client_secret);
-    
-    match initiate_auth_fluent_builder.send().await{
+
+    match initiate_auth_fluent_builder.send().await {
         Ok(response) => {
             let access_token = response.authentication_result().unwrap().access_token().unwrap();
             let id_token = response.authentication_result().unwrap().id_token().unwrap();
@@ -225,31 +374,69 @@ pub async fn login(
                 "id_token":id_token,
                 "refresh_token":refresh_token
             })});
-            Ok((StatusCode::OK,Json(success_response)))
-        },
-        Err(error) => {
-            let error_response = serde_json::json!({
-                "status": "error","message": format!("{:?}", error)
-            });
-            Err((StatusCode::OK,Json(error_response)))
-        },
+            Ok((StatusCode::OK, Json(success_response)))
+        }
+        Err(_) => Err(AppError::InvalidCredentials),
     }
+}
 
+#[utoipa::path(
+    post,
+    path = "/refresh",
+    tag = "auth",
+    request_body = RefreshSchema,
+    responses(
+        (status = 200, description = "Refresh succeeded, returns new access/id tokens"),
+        (status = 401, description = "Invalid or expired refresh token")
+    )
+)]
+pub async fn refresh(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<RefreshSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_id = data.config.client_id.clone();
+
+    let client_secret = generate_secret_hash(&data.config.client_secret, &body.username, &client_id);
+
+    let initiate_auth_fluent_builder = data
+        .client
+        .initiate_auth()
+        .client_id(client_id)
+        .auth_flow(AuthFlowType::RefreshTokenAuth)
+        .auth_parameters("REFRESH_TOKEN", &body.refresh_token)
+        .auth_parameters("SECRET_HASH", client_secret);
+
+    match initiate_auth_fluent_builder.send().await {
+        Ok(response) => {
+            let access_token = response.authentication_result().unwrap().access_token().unwrap();
+            let id_token = response.authentication_result().unwrap().id_token().unwrap();
+            let success_response = serde_json::json!({"status": "success","data": serde_json::json!({
+                "access_token": access_token,
+                "id_token": id_token
+            })});
+            Ok((StatusCode::OK, Json(success_response)))
+        }
+        Err(_) => Err(AppError::InvalidCredentials),
+    }
 }
 
+#[utoipa::path(
+    post,
+    path = "/signup",
+    tag = "auth",
+    request_body = SignupSchema,
+    responses(
+        (status = 201, description = "User created in Cognito"),
+        (status = 400, description = "Cognito rejected the signup request")
+    )
+)]
 pub async fn signup(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<SignupSchema>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    let client_id = std::env::var("CLIENT_ID").unwrap();
-
-    let client_secret = generate_secret_hash(
-        &std::env::var("CLIENT_SECRET").unwrap(),
-        &body.username,
-        &client_id,
-    );
+    ValidatedJson(body): ValidatedJson<SignupSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_id = data.config.client_id.clone();
 
-    let _user_pool_id = std::env::var("USER_POOL_ID").unwrap();
+    let client_secret = generate_secret_hash(&data.config.client_secret, &body.username, &client_id);
 
     let user_attribute_email = AttributeTypeBuilder::default()
         .name("email")
@@ -280,26 +467,27 @@ pub async fn signup(
 
             Ok((StatusCode::CREATED, Json(success_response)))
         }
-        Err(error) => {
-            let error_response = serde_json::json!({
-                "status": "error","message": format!("{}",error.to_string())
-            });
-            Err((StatusCode::BAD_REQUEST, Json(error_response)))
-        }
+        Err(error) => Err(AppError::Cognito(error.to_string())),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/confirm",
+    tag = "auth",
+    request_body = ConfirmUserSchema,
+    responses(
+        (status = 200, description = "User confirmed"),
+        (status = 400, description = "Cognito rejected the confirmation code")
+    )
+)]
 pub async fn confirm_user(
     State(data): State<Arc<AppState>>,
-    Json(body): Json<ConfirmUserSchema>,
-) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
-    let client_id = std::env::var("CLIENT_ID").unwrap();
+    ValidatedJson(body): ValidatedJson<ConfirmUserSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_id = data.config.client_id.clone();
 
-    let client_secret = generate_secret_hash(
-        &std::env::var("CLIENT_SECRET").unwrap(),
-        &body.username,
-        &client_id,
-    );
+    let client_secret = generate_secret_hash(&data.config.client_secret, &body.username, &client_id);
 
     let confirm_signup_fluent_builder = data
         .client
@@ -316,12 +504,81 @@ pub async fn confirm_user(
             });
             Ok((StatusCode::OK, Json(success_response)))
         }
-        Err(error) => {
-            let error_response = serde_json::json!({
-                "status": "error","message": format!("{}",error.to_string())
+        Err(error) => Err(AppError::Cognito(error.to_string())),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/forgot-password",
+    tag = "auth",
+    request_body = ForgotPasswordSchema,
+    responses(
+        (status = 200, description = "Confirmation code emailed to the user"),
+        (status = 400, description = "Cognito rejected the forgot-password request")
+    )
+)]
+pub async fn forgot_password(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<ForgotPasswordSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_id = data.config.client_id.clone();
+
+    let client_secret = generate_secret_hash(&data.config.client_secret, &body.username, &client_id);
+
+    let forgot_password_fluent_builder = data
+        .client
+        .forgot_password()
+        .client_id(client_id)
+        .secret_hash(client_secret)
+        .username(&body.username);
+
+    match forgot_password_fluent_builder.send().await {
+        Ok(_) => {
+            let success_response = serde_json::json!({
+                "status": "success","message": "Confirmation code sent. Check email to reset your password."
             });
-            Err((StatusCode::OK, Json(error_response)))
+            Ok((StatusCode::OK, Json(success_response)))
+        }
+        Err(error) => Err(AppError::Cognito(error.to_string())),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/confirm-forgot-password",
+    tag = "auth",
+    request_body = ConfirmForgotPasswordSchema,
+    responses(
+        (status = 200, description = "Password reset"),
+        (status = 400, description = "Cognito rejected the confirmation code or new password")
+    )
+)]
+pub async fn confirm_forgot_password(
+    State(data): State<Arc<AppState>>,
+    ValidatedJson(body): ValidatedJson<ConfirmForgotPasswordSchema>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_id = data.config.client_id.clone();
+
+    let client_secret = generate_secret_hash(&data.config.client_secret, &body.username, &client_id);
+
+    let confirm_forgot_password_fluent_builder = data
+        .client
+        .confirm_forgot_password()
+        .client_id(client_id)
+        .secret_hash(client_secret)
+        .username(&body.username)
+        .confirmation_code(&body.confirmation_code)
+        .password(&body.new_password);
+
+    match confirm_forgot_password_fluent_builder.send().await {
+        Ok(_) => {
+            let success_response = serde_json::json!({
+                "status": "success","message": "Password reset. You can now log in with your new password."
+            });
+            Ok((StatusCode::OK, Json(success_response)))
         }
+        Err(error) => Err(AppError::Cognito(error.to_string())),
     }
 }
 
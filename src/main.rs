@@ -1,3 +1,7 @@
+mod config;
+mod doc;
+mod error;
+mod extractor;
 mod handler;
 mod middleware;
 mod model;
@@ -16,19 +20,31 @@ use aws_sdk_cognitoidentityprovider as cognitoidentity;
 
 use cognitoidentity::Client;
 
+use jsonwebtokens::Verifier;
+use jsonwebtokens_cognito::KeySet;
+
 use sqlx::{migrate::MigrateDatabase, sqlite::SqlitePoolOptions, Pool, Sqlite};
+use tokio::sync::broadcast;
 use tower_http::cors::CorsLayer;
 
-use std::{net::SocketAddr, sync::Arc};
+use std::sync::Arc;
 
 use dotenv::dotenv;
 
-use crate::route::create_router;
+use crate::{config::Config, model::TodoEvent, route::create_router};
+
+// Number of buffered todo events a slow SSE subscriber can fall behind by before it
+// starts missing updates.
+const TODO_EVENTS_CAPACITY: usize = 100;
 
 // Struct representing the application state
 pub struct AppState {
     db: Pool<Sqlite>,
     client: Client,
+    config: Config,
+    keyset: KeySet,
+    token_verifier: Verifier,
+    todo_events: broadcast::Sender<TodoEvent>,
 }
 
 // Entry point of the application
@@ -36,15 +52,25 @@ pub struct AppState {
 async fn main() {
     dotenv().ok();
 
-    let config = aws_config::load_from_env().await;
-    let client = aws_sdk_cognitoidentityprovider::Client::new(&config);
+    let config = Config::from_env().unwrap_or_else(|err| {
+        eprintln!("🔥 Invalid configuration: {}", err);
+        std::process::exit(1);
+    });
 
-    const DB_URL: &str = "sqlite://todo.db";
+    let aws_config = aws_config::load_from_env().await;
+    let client = aws_sdk_cognitoidentityprovider::Client::new(&aws_config);
+
+    let keyset = KeySet::new(config.user_pool_region.clone(), config.user_pool_id.clone())
+        .expect("failed to build Cognito key set from configuration");
+    let token_verifier = keyset
+        .new_access_token_verifier(&[&config.client_id])
+        .build()
+        .expect("failed to build Cognito access token verifier");
 
     // Check if the database exists, if not, create it
-    if !Sqlite::database_exists(DB_URL).await.unwrap_or(false) {
-        println!("Creating database {}", DB_URL);
-        match Sqlite::create_database(DB_URL).await {
+    if !Sqlite::database_exists(&config.db_url).await.unwrap_or(false) {
+        println!("Creating database {}", config.db_url);
+        match Sqlite::create_database(&config.db_url).await {
             Ok(_) => println!("Create db success"),
             Err(error) => panic!("error: {}", error),
         }
@@ -55,7 +81,7 @@ async fn main() {
     // Connect to the database
     let pool = match SqlitePoolOptions::new()
         .max_connections(10)
-        .connect(DB_URL)
+        .connect(&config.db_url)
         .await
     {
         Ok(pool) => {
@@ -83,29 +109,37 @@ async fn main() {
 
     println!("Create todo table");
 
-    // Create an Arc-wrapped instance of the application state
-    let app_state = Arc::new(AppState {
-        db: pool.clone(),
-        client: client.clone(),
-    });
-
     // Configure CORS settings for the application
     let cors = CorsLayer::new()
-        .allow_origin("http://localhost:3000".parse::<HeaderValue>().unwrap())
+        .allow_origin(
+            config
+                .cors_origin
+                .parse::<HeaderValue>()
+                .expect("CORS_ORIGIN must be a valid header value"),
+        )
         .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE])
         .allow_credentials(true)
         .allow_headers([AUTHORIZATION, ACCEPT, CONTENT_TYPE]);
 
+    // Create an Arc-wrapped instance of the application state
+    let (todo_events, _) = broadcast::channel(TODO_EVENTS_CAPACITY);
+    let bind_address = config.bind_address;
+    let app_state = Arc::new(AppState {
+        db: pool.clone(),
+        client: client.clone(),
+        config,
+        keyset,
+        token_verifier,
+        todo_events,
+    });
+
     // Create the Axum application with routes and middleware
     let app = create_router(app_state).layer(cors);
 
     println!("🚀 Server started successfully");
 
-    // Specify the address and port to run the server on
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
-
     // Start the Axum server
-    Server::bind(&addr)
+    Server::bind(&bind_address)
         .serve(app.into_make_service())
         .await
         .unwrap();
@@ -1,17 +1,18 @@
+use std::sync::Arc;
+
 use axum::{
+    extract::State,
     http::{self, Request, StatusCode},
     middleware::Next,
     response::Response,
 };
 
-use jsonwebtokens_cognito::KeySet;
 use serde_json::Value;
 
-use crate::model::CurrentUser;
-
-
+use crate::{model::CurrentUser, AppState};
 
 pub async fn mw_require_auth<B>(
+    State(data): State<Arc<AppState>>,
     mut request: Request<B>,
     next: Next<B>,
 ) -> Result<Response, StatusCode> {
@@ -26,17 +27,7 @@ pub async fn mw_require_auth<B>(
         return Err(StatusCode::UNAUTHORIZED);
     };
 
-    let user_pool_region = std::env::var("USER_POOL_REGION").unwrap();
-    let user_pool_id = std::env::var("USER_POOL_ID").unwrap();
-    let client_id = std::env::var("CLIENT_ID").unwrap();
-
-    let keyset = KeySet::new(user_pool_region, user_pool_id).unwrap();
-    let verifier = keyset
-        .new_access_token_verifier(&[&client_id])
-        .build()
-        .unwrap();
-
-    match keyset.verify(&auth_header, &verifier).await {
+    match data.keyset.verify(&auth_header, &data.token_verifier).await {
         Ok(result) => {
             // Match on the Value to ensure it's an object with the "username" field
             if let Value::Object(obj) = result {
@@ -1,6 +1,6 @@
 
 // Data model representing a Todo item
-#[derive(Debug, sqlx::FromRow, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, sqlx::FromRow, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
 pub struct Todo {
     pub(crate) id: i32,
     pub(crate) task: String,
@@ -10,4 +10,13 @@ pub struct Todo {
 #[derive(Debug,Clone)]
 pub struct CurrentUser{
     pub(crate) username:String
+}
+
+// Event published on `AppState::todo_events` whenever a todo is created, updated, or
+// deleted, so the SSE handler can notify the owning user without polling the database.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TodoEvent {
+    pub action: String,
+    pub id: i32,
+    pub username: String,
 }
\ No newline at end of file
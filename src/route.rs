@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
 use axum::{
-    middleware::from_fn,
+    middleware::from_fn_with_state,
     routing::{get, post},
     Router,
 };
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{handler::*, middleware::mw_require_auth, AppState};
+use crate::{doc::ApiDoc, handler::*, middleware::mw_require_auth, AppState};
 
 pub fn create_router(app_state: Arc<AppState>) -> Router {
     let app = Router::new()
@@ -15,12 +17,18 @@ pub fn create_router(app_state: Arc<AppState>) -> Router {
             "/todos/:id",
             get(get_todo).patch(update_todo).delete(delete_todo),
         )
+        .route("/todos/events", get(todo_events))
         .route("/logout", post(logout))
-        .route_layer(from_fn(mw_require_auth))
+        .route_layer(from_fn_with_state(app_state.clone(), mw_require_auth))
         .route("/login", post(login))
+        .route("/refresh", post(refresh))
         .route("/signup", post(signup))
         .route("/confirm", post(confirm_user))
+        .route("/forgot-password", post(forgot_password))
+        .route("/confirm-forgot-password", post(confirm_forgot_password))
         .route("/", get(health_checker_handler))
+        .route("/health/ready", get(readiness_handler))
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         .with_state(app_state);
     app
 }
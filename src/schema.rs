@@ -1,32 +1,74 @@
 // Struct representing the request body for creating a new Todo
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
 pub struct CreateTodoSchema {
+    #[validate(length(min = 1, message = "task must not be empty"))]
     pub task: String,
     pub completed: bool,
 }
 
 // Struct representing the request body for updating a Todo
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
 pub struct UpdateTodoSchema {
+    #[validate(length(min = 1, message = "task must not be empty"))]
     pub task: String,
     pub completed: bool,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
 pub struct SignupSchema {
+    #[validate(length(min = 3, max = 64, message = "username must be 3-64 characters"))]
     pub username: String,
+    #[validate(email(message = "email must be a valid email address"))]
     pub email: String,
+    #[validate(length(min = 8, max = 128, message = "password must be 8-128 characters"))]
     pub password: String,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
 pub struct ConfirmUserSchema {
+    #[validate(length(min = 3, max = 64, message = "username must be 3-64 characters"))]
     pub username: String,
+    #[validate(length(min = 1, message = "confirmation_code must not be empty"))]
     pub confirmation_code: String,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
 pub struct LoginSchema{
+    #[validate(length(min = 1, message = "username must not be empty"))]
     pub username:String,
+    #[validate(length(min = 1, message = "password must not be empty"))]
     pub password:String
-}
\ No newline at end of file
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
+pub struct RefreshSchema {
+    #[validate(length(min = 1, message = "username must not be empty"))]
+    pub username: String,
+    #[validate(length(min = 1, message = "refresh_token must not be empty"))]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
+pub struct ForgotPasswordSchema {
+    #[validate(length(min = 1, message = "username must not be empty"))]
+    pub username: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema, validator::Validate)]
+pub struct ConfirmForgotPasswordSchema {
+    #[validate(length(min = 1, message = "username must not be empty"))]
+    pub username: String,
+    #[validate(length(min = 1, message = "confirmation_code must not be empty"))]
+    pub confirmation_code: String,
+    #[validate(length(min = 8, max = 128, message = "new_password must be 8-128 characters"))]
+    pub new_password: String,
+}
+
+// Query parameters accepted by `GET /todos` for paging, filtering, and sorting.
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::IntoParams)]
+pub struct ListOptions {
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+    pub completed: Option<bool>,
+    pub sort: Option<String>,
+}